@@ -19,11 +19,29 @@ use std::io::Write;
 /// }
 /// ```
 pub fn read(file_name: &String) -> Result<String, io::Error> {
+    let data = read_bytes(file_name)?;
+
+    Ok(String::from_utf8_lossy(&data).into_owned())
+}
+
+/// read_bytes() is get the raw bytes of a file.
+///
+/// # Examples
+/// ```no_run
+/// use common_library::file::read_bytes;
+///
+/// let file_name = String::from("test.txt");
+/// match read_bytes(&file_name) {
+///     Ok(_data) => println!("{:?}", _data),
+///     Err(e) => println!("{}", e),
+/// }
+/// ```
+pub fn read_bytes(file_name: &String) -> Result<Vec<u8>, io::Error> {
     let mut file = File::open(file_name)?;
 
-    let mut data = String::new();
+    let mut data = Vec::new();
 
-    file.read_to_string(&mut data)?;
+    file.read_to_end(&mut data)?;
 
     Ok(data)
 }
@@ -42,9 +60,26 @@ pub fn read(file_name: &String) -> Result<String, io::Error> {
 /// }
 /// ```
 pub fn write(file_name: &String, data: &String) -> Result<(), io::Error> {
+    write_bytes(file_name, data.as_bytes())
+}
+
+/// write_bytes() is write raw bytes to file.
+///
+/// # Examples
+/// ```no_run
+/// use common_library::file::write_bytes;
+///
+/// let file_name = String::from("test.txt");
+/// let data = b"test data";
+/// match write_bytes(&file_name, data) {
+///     Ok(_) => println!("Ok"),
+///     Err(e) => println!("{}", e),
+/// }
+/// ```
+pub fn write_bytes(file_name: &String, data: &[u8]) -> Result<(), io::Error> {
     let mut file = File::create(file_name)?;
 
-    file.write_all(data.as_bytes())?;
+    file.write_all(data)?;
 
     Ok(())
 }
@@ -102,4 +137,30 @@ mod tests {
     fn remove_test() {
         read_test();
     }
+
+    #[test]
+    fn read_bytes_test() {
+        let file_name = String::from("file-test-") + &Uuid::new_v4().to_string();
+        let data: Vec<u8> = vec![0, 159, 146, 150, 0, 1];
+
+        match write_bytes(&file_name, &data) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        }
+
+        match read_bytes(&file_name) {
+            Ok(_data) => assert_eq!(_data, data),
+            Err(e) => assert!(false, "{}", e),
+        }
+
+        match remove(&file_name) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        }
+    }
+
+    #[test]
+    fn write_bytes_test() {
+        read_bytes_test();
+    }
 }
@@ -1,11 +1,19 @@
 use std::io::prelude::*;
+use std::io::BufReader;
 use std::net::SocketAddr;
 use std::net::TcpStream;
 use std::time::Duration;
 
+use crate::socket::frame::{read_frame, write_frame};
+
 /// Client is object that provides client infomation.
 pub struct Client {
+    // `stream` and `reader` are independent clones of the same socket, so
+    // every reading method must go through `reader`: the two clones are
+    // separate file descriptors, and a raw read on `stream` would not see
+    // bytes the BufReader already pulled into its internal buffer.
     stream: Option<TcpStream>,
+    reader: Option<BufReader<TcpStream>>,
 }
 
 impl Client {
@@ -18,7 +26,10 @@ impl Client {
     /// let mut client = Client::new();
     /// ```
     pub fn new() -> Client {
-        Client { stream: None }
+        Client {
+            stream: None,
+            reader: None,
+        }
     }
 
     /// connect() is connect to the address.
@@ -47,7 +58,9 @@ impl Client {
     pub fn connect(&mut self, address: SocketAddr, timeout: Duration) -> Result<(), String> {
         match TcpStream::connect_timeout(&address, timeout) {
             Ok(stream) => {
+                let reader_stream = stream.try_clone().map_err(|e| e.to_string())?;
                 self.stream = Some(stream);
+                self.reader = Some(BufReader::new(reader_stream));
                 Ok(())
             }
             Err(e) => Err(e.to_string()),
@@ -88,13 +101,151 @@ impl Client {
     /// };
     /// ```
     pub fn read(&mut self, receive_size: usize) -> Result<String, String> {
+        let data = self.read_bytes(receive_size)?;
+
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
+
+    /// read_bytes() is read raw bytes, with no UTF-8 decoding or NUL trimming.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::SendMessage(vec![0, 159, 146, 150]),
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// match client.read_bytes(1024) {
+    ///     Ok(data) => assert_eq!(data[..4], [0, 159, 146, 150]),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    /// ```
+    pub fn read_bytes(&mut self, receive_size: usize) -> Result<Vec<u8>, String> {
         let mut buffer = vec![0; receive_size];
 
-        if let Some(stream) = &mut self.stream {
-            match stream.read(&mut buffer) {
-                Ok(_) => Ok(String::from(
-                    String::from_utf8_lossy(&buffer.to_vec()).trim_end_matches(char::from(0)),
-                )),
+        if let Some(reader) = &mut self.reader {
+            match reader.read(&mut buffer) {
+                Ok(n) => {
+                    buffer.truncate(n);
+                    Ok(buffer)
+                }
+                Err(e) => Err(e.to_string()),
+            }
+        } else {
+            Err(String::from("please call the connect function first"))
+        }
+    }
+
+    /// read_line() reads a single `\n`-terminated line from the connection,
+    /// buffering any extra bytes that arrived in the same packet so the
+    /// next call picks up exactly where this one left off.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::SendMessage("greeting\r\n".as_bytes().to_vec()),
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// match client.read_line() {
+    ///     Ok(line) => assert_eq!(line, "greeting\r\n"),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    /// ```
+    pub fn read_line(&mut self) -> Result<String, String> {
+        if let Some(reader) = &mut self.reader {
+            let mut line = String::new();
+
+            match reader.read_line(&mut line) {
+                Ok(_) => Ok(line),
+                Err(e) => Err(e.to_string()),
+            }
+        } else {
+            Err(String::from("please call the connect function first"))
+        }
+    }
+
+    /// read_until() reads bytes up to and including `delim`, buffering any
+    /// extra bytes that arrived in the same packet for the next read.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::SendMessage("greeting\r\n".as_bytes().to_vec()),
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// match client.read_until(b'\n') {
+    ///     Ok(data) => assert_eq!(data, "greeting\r\n".as_bytes()),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    /// ```
+    pub fn read_until(&mut self, delim: u8) -> Result<Vec<u8>, String> {
+        if let Some(reader) = &mut self.reader {
+            let mut data = Vec::new();
+
+            match reader.read_until(delim, &mut data) {
+                Ok(_) => Ok(data),
                 Err(e) => Err(e.to_string()),
             }
         } else {
@@ -102,6 +253,52 @@ impl Client {
         }
     }
 
+    /// recv_frame() reads back a single length-prefixed frame written by a
+    /// peer's `send_frame`/`write_frame`, see [`crate::socket::frame`].
+    /// `max_frame_size` rejects a frame claiming to be larger than that,
+    /// to avoid an unbounded allocation off a malicious or corrupt length
+    /// prefix.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::SendMessage(vec![0, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']),
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// match client.recv_frame(1024) {
+    ///     Ok(data) => assert_eq!(data, b"hello"),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    /// ```
+    pub fn recv_frame(&mut self, max_frame_size: u32) -> Result<Vec<u8>, String> {
+        if let Some(reader) = &mut self.reader {
+            read_frame(reader, max_frame_size)
+        } else {
+            Err(String::from("please call the connect function first"))
+        }
+    }
+
     /// write() is write data.
     /// # Examples
     /// ```
@@ -138,8 +335,49 @@ impl Client {
     ///
     /// assert_eq!(std::str::from_utf8(&*http_server_mocker.pop_received_message().unwrap()).unwrap(), data)
     pub fn write(&mut self, data: &String) -> Result<(), String> {
+        self.write_bytes(data.as_bytes())
+    }
+
+    /// write_bytes() is write raw bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::SendMessage("".into()),
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0, 159, 146, 150];
+    /// match client.write_bytes(&data) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// assert_eq!(*http_server_mocker.pop_received_message().unwrap(), data)
+    /// ```
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), String> {
         if let Some(stream) = &mut self.stream {
-            match stream.write(data.as_bytes()) {
+            match stream.write_all(data) {
                 Ok(_) => Ok(()),
                 Err(e) => Err(e.to_string()),
             }
@@ -147,20 +385,63 @@ impl Client {
             Err(String::from("please call the connect function first"))
         }
     }
+
+    /// send_frame() writes `data` as a single length-prefixed frame, see
+    /// [`crate::socket::frame`].
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::client::Client;
+    /// use socket_server_mocker::server_mocker::ServerMocker;
+    /// use socket_server_mocker::server_mocker_instruction::ServerMockerInstruction;
+    /// use socket_server_mocker::tcp_server_mocker::TcpServerMocker;
+    /// use std::net::SocketAddr;
+    /// use std::net::ToSocketAddrs;
+    /// use std::time::Duration;
+    ///
+    /// let http_server_mocker = TcpServerMocker::new(0).unwrap();
+    /// http_server_mocker.add_mock_instructions(&[
+    ///     ServerMockerInstruction::ReceiveMessage,
+    ///     ServerMockerInstruction::StopExchange,
+    /// ]);
+    ///
+    /// let mut client = Client::new();
+    ///
+    /// match client.connect(
+    ///     SocketAddr::from(([127, 0, 0, 1], http_server_mocker.listening_port())),
+    ///     Duration::new(3, 0),
+    /// ) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// match client.send_frame(b"hello") {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     *http_server_mocker.pop_received_message().unwrap(),
+    ///     [0, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']
+    /// )
+    /// ```
+    pub fn send_frame(&mut self, data: &[u8]) -> Result<(), String> {
+        if let Some(stream) = &mut self.stream {
+            write_frame(stream, data)
+        } else {
+            Err(String::from("please call the connect function first"))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate rand;
-
     use super::*;
     use crate::socket::server::Server;
-    use rand::Rng;
 
     #[test]
     fn connect_test() {
         let mut server = Server::new();
-        let port = rand::thread_rng().gen_range(11000..12000);
 
         let job = |mut stream: TcpStream| {
             match stream.write("--- greeting ---\r\n".as_bytes()) {
@@ -180,11 +461,15 @@ mod tests {
             }
         };
 
-        match server.start(String::from("0.0.0.0:") + &port.to_string(), job) {
+        match server.start("127.0.0.1:0", job) {
             Ok(_) => (),
             Err(e) => assert!(false, "{}", e),
         };
 
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
         {
             let mut client = Client::new();
 
@@ -220,4 +505,355 @@ mod tests {
     fn write_test() {
         connect_test();
     }
+
+    #[test]
+    fn read_bytes_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match stream.write(&[0, 159, 146, 150, 0, 1]) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            let mut buffer = [0; 1024];
+            match stream.read(&mut buffer) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_bytes(1024) {
+                Ok(data) => assert_eq!(data[..6], [0, 159, 146, 150, 0, 1]),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            let data: Vec<u8> = vec![0, 1, 2, 3];
+            match client.write_bytes(&data) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+        }
+
+        server.stop();
+    }
+
+    #[test]
+    fn write_bytes_test() {
+        read_bytes_test();
+    }
+
+    #[test]
+    fn read_line_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match stream.write("first\r\nsecond\r\n".as_bytes()) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            let mut buffer = [0; 1024];
+            match stream.read(&mut buffer) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_line() {
+                Ok(line) => assert_eq!(line, "first\r\n"),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_line() {
+                Ok(line) => assert_eq!(line, "second\r\n"),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            let data = String::from("done\r\n");
+            match client.write(&data) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+        }
+
+        server.stop();
+    }
+
+    #[test]
+    fn read_line_then_read_bytes_sees_remaining_data_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match stream.write("first\r\nsecond\r\n".as_bytes()) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            let mut buffer = [0; 1024];
+            match stream.read(&mut buffer) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_line() {
+                Ok(line) => assert_eq!(line, "first\r\n"),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_bytes(1024) {
+                Ok(data) => assert_eq!(data, "second\r\n".as_bytes()),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            let data = String::from("done\r\n");
+            match client.write(&data) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+        }
+
+        server.stop();
+    }
+
+    #[test]
+    fn read_until_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match stream.write(&[1, 2, 3, 0, 4, 5, 0]) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            let mut buffer = [0; 1024];
+            match stream.read(&mut buffer) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_until(0) {
+                Ok(data) => assert_eq!(data, vec![1, 2, 3, 0]),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.read_until(0) {
+                Ok(data) => assert_eq!(data, vec![4, 5, 0]),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            let data: Vec<u8> = vec![9];
+            match client.write_bytes(&data) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+        }
+
+        server.stop();
+    }
+
+    #[test]
+    fn send_frame_and_recv_frame_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match read_frame(&mut stream, 1024) {
+                Ok(data) => assert_eq!(data, b"hello"),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match write_frame(&mut stream, b"world") {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.send_frame(b"hello") {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.recv_frame(1024) {
+                Ok(data) => assert_eq!(data, b"world"),
+                Err(e) => assert!(false, "{}", e),
+            };
+        }
+
+        server.stop();
+    }
+
+    #[test]
+    fn recv_frame_rejects_oversized_frame_test() {
+        let mut server = Server::new();
+
+        let job = |mut stream: TcpStream| {
+            match write_frame(&mut stream, &[0; 128]) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer);
+        };
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        {
+            let mut client = Client::new();
+
+            match client.connect(
+                SocketAddr::from(([127, 0, 0, 1], port)),
+                Duration::new(3, 0),
+            ) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            };
+
+            match client.recv_frame(16) {
+                Ok(_) => assert!(false, "expected oversized frame to be rejected"),
+                Err(_) => (),
+            };
+        }
+
+        server.stop();
+    }
 }
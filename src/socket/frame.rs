@@ -0,0 +1,126 @@
+//! Crate socket::frame provides length-prefixed message framing over any
+//! `Read`/`Write` stream, so callers get message boundaries without having
+//! to hand-roll delimiter scanning.
+
+use std::io::Read;
+use std::io::Write;
+
+/// LENGTH_PREFIX_SIZE is the number of bytes used to encode a frame's
+/// payload length, as a big-endian `u32`.
+const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// write_frame() writes `data` prefixed with its length as a 4-byte
+/// big-endian `u32`.
+///
+/// # Examples
+/// ```
+/// use common_library::socket::frame::write_frame;
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// match write_frame(&mut buffer, b"hello") {
+///     Ok(_) => (),
+///     Err(e) => assert!(false, "{}", e),
+/// }
+/// assert_eq!(buffer, [0, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']);
+/// ```
+pub fn write_frame<W: Write>(stream: &mut W, data: &[u8]) -> Result<(), String> {
+    let length = u32::try_from(data.len()).map_err(|e| e.to_string())?;
+
+    stream
+        .write_all(&length.to_be_bytes())
+        .map_err(|e| e.to_string())?;
+    stream.write_all(data).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// read_frame() reads back a single frame written by [`write_frame`].
+/// `max_frame_size` bounds the payload length the caller is willing to
+/// allocate for; a frame claiming to be larger is rejected without
+/// reading its payload.
+///
+/// # Examples
+/// ```
+/// use common_library::socket::frame::read_frame;
+/// use common_library::socket::frame::write_frame;
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// match write_frame(&mut buffer, b"hello") {
+///     Ok(_) => (),
+///     Err(e) => assert!(false, "{}", e),
+/// }
+///
+/// match read_frame(&mut buffer.as_slice(), 1024) {
+///     Ok(data) => assert_eq!(data, b"hello"),
+///     Err(e) => assert!(false, "{}", e),
+/// }
+/// ```
+pub fn read_frame<R: Read>(stream: &mut R, max_frame_size: u32) -> Result<Vec<u8>, String> {
+    let mut length_buffer = [0; LENGTH_PREFIX_SIZE];
+
+    stream
+        .read_exact(&mut length_buffer)
+        .map_err(|e| e.to_string())?;
+
+    let length = u32::from_be_bytes(length_buffer);
+
+    if length > max_frame_size {
+        return Err(format!(
+            "frame length {} exceeds max frame size {}",
+            length, max_frame_size
+        ));
+    }
+
+    let mut data = vec![0; length as usize];
+
+    stream.read_exact(&mut data).map_err(|e| e.to_string())?;
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_frame_test() {
+        let mut buffer: Vec<u8> = Vec::new();
+
+        match write_frame(&mut buffer, b"hello") {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        }
+
+        assert_eq!(buffer, [0, 0, 0, 5, b'h', b'e', b'l', b'l', b'o']);
+    }
+
+    #[test]
+    fn read_frame_test() {
+        let mut buffer: Vec<u8> = Vec::new();
+
+        match write_frame(&mut buffer, b"hello") {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        }
+
+        match read_frame(&mut buffer.as_slice(), 1024) {
+            Ok(data) => assert_eq!(data, b"hello"),
+            Err(e) => assert!(false, "{}", e),
+        }
+    }
+
+    #[test]
+    fn read_frame_rejects_oversized_frame_test() {
+        let mut buffer: Vec<u8> = Vec::new();
+
+        match write_frame(&mut buffer, b"hello") {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        }
+
+        match read_frame(&mut buffer.as_slice(), 4) {
+            Ok(_) => assert!(false, "expected oversized frame to be rejected"),
+            Err(_) => (),
+        }
+    }
+}
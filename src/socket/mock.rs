@@ -0,0 +1,548 @@
+//! Crate socket::mock provides an HTTP request-matching mock server built
+//! on top of [`crate::socket::server::Server`], modeled on mockito, so the
+//! crate's socket primitives can test HTTP clients without an external
+//! dependency.
+
+use crate::socket::server::Server;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Body is the expectation a mock places on a request body, matching
+/// mockito's distinction between text and raw-binary body matching.
+#[derive(Clone)]
+enum Body {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Expectation is a single registered request/response pairing, kept in
+/// the `MockServer`'s shared list and consulted by its connection
+/// handlers; the `Mock` handle returned to the caller shares only its hit
+/// counter.
+struct Expectation {
+    method: String,
+    path: String,
+    match_headers: Vec<(String, String)>,
+    match_body: Option<Body>,
+    status_line: String,
+    response_headers: Vec<(String, String)>,
+    response_body: Vec<u8>,
+    hits: Arc<AtomicUsize>,
+}
+
+impl Expectation {
+    fn matches(&self, method: &str, path: &str, headers: &[(String, String)], body: &[u8]) -> bool {
+        if !self.method.eq_ignore_ascii_case(method) || self.path != path {
+            return false;
+        }
+
+        for (field, value) in &self.match_headers {
+            let found = headers
+                .iter()
+                .any(|(f, v)| f.eq_ignore_ascii_case(field) && v == value);
+
+            if !found {
+                return false;
+            }
+        }
+
+        match &self.match_body {
+            Some(Body::Text(text)) => String::from_utf8_lossy(body) == *text,
+            Some(Body::Binary(bytes)) => body == bytes.as_slice(),
+            None => true,
+        }
+    }
+
+    /// response_bytes() renders the full HTTP response for a match, so it
+    /// can be cloned out from under the expectations lock before the
+    /// caller performs the blocking write.
+    fn response_bytes(&self) -> Vec<u8> {
+        let mut response = Vec::new();
+
+        response.extend_from_slice(self.status_line.as_bytes());
+        response.extend_from_slice(b"\r\n");
+
+        for (field, value) in &self.response_headers {
+            response.extend_from_slice(format!("{}: {}\r\n", field, value).as_bytes());
+        }
+
+        response.extend_from_slice(format!("content-length: {}\r\n", self.response_body.len()).as_bytes());
+        response.extend_from_slice(b"\r\n");
+        response.extend_from_slice(&self.response_body);
+
+        response
+    }
+}
+
+/// Request is a parsed incoming HTTP request, kept just long enough to be
+/// matched against registered expectations.
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream.try_clone().ok()?);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).ok()? == 0 {
+        return None;
+    }
+
+    let mut parts = request_line.trim_end().splitn(3, ' ');
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut headers: Vec<(String, String)> = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            break;
+        }
+
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((field, value)) = line.split_once(':') {
+            headers.push((field.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let content_length = headers
+        .iter()
+        .find(|(field, _)| field.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = vec![0; content_length];
+    if content_length > 0 {
+        use std::io::Read;
+        reader.read_exact(&mut body).ok()?;
+    }
+
+    Some(Request {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
+
+fn handle_connection(mut stream: TcpStream, expectations: &Arc<Mutex<Vec<Expectation>>>) {
+    let Some(request) = read_request(&stream) else {
+        return;
+    };
+
+    // Render the response bytes while the lock is held, then drop the
+    // guard before writing: `write_all` is blocking I/O, and holding this
+    // mutex through it would serialize every connection handled by
+    // `Server`'s one-thread-per-connection job behind whichever response
+    // is currently flushing.
+    let response = {
+        let expectations = expectations.lock().unwrap();
+        let matched = expectations.iter().find(|expectation| {
+            expectation.matches(&request.method, &request.path, &request.headers, &request.body)
+        });
+
+        match matched {
+            Some(expectation) => {
+                expectation.hits.fetch_add(1, Ordering::Relaxed);
+                expectation.response_bytes()
+            }
+            None => b"HTTP/1.1 501 Not Implemented\r\ncontent-length: 0\r\n\r\n".to_vec(),
+        }
+    };
+
+    let _ = stream.write_all(&response);
+    let _ = stream.flush();
+}
+
+/// Mock is the handle returned by [`MockBuilder::create`] used to assert on
+/// how many requests the expectation actually matched.
+pub struct Mock {
+    hits: Arc<AtomicUsize>,
+    min_hits: usize,
+    max_hits: Option<usize>,
+}
+
+impl Mock {
+    /// hits() returns how many requests this mock has matched so far.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// assert_hits() panics unless the mock matched exactly `expected`
+    /// requests.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::mock::MockServer;
+    ///
+    /// let mut server = MockServer::start().unwrap();
+    /// let mock = server.mock("GET", "/hello").with_body("world").create();
+    ///
+    /// mock.assert_hits(0);
+    ///
+    /// server.stop();
+    /// ```
+    pub fn assert_hits(&self, expected: usize) {
+        let hits = self.hits();
+        assert_eq!(hits, expected, "expected {} hit(s), found {}", expected, hits);
+    }
+
+    /// is_missing_hits() reports whether this mock has matched fewer
+    /// requests than the minimum set by [`MockBuilder::expect_at_least`]
+    /// (one, by default).
+    pub fn is_missing_hits(&self) -> bool {
+        self.hits() < self.min_hits
+    }
+
+    /// exceeds_max_hits() reports whether this mock has matched more
+    /// requests than the maximum set by [`MockBuilder::expect_at_most`]
+    /// (unbounded, by default).
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::mock::MockServer;
+    ///
+    /// let mut server = MockServer::start().unwrap();
+    /// let mock = server
+    ///     .mock("GET", "/hello")
+    ///     .expect_at_most(0)
+    ///     .create();
+    ///
+    /// assert!(!mock.exceeds_max_hits());
+    ///
+    /// server.stop();
+    /// ```
+    pub fn exceeds_max_hits(&self) -> bool {
+        match self.max_hits {
+            Some(max_hits) => self.hits() > max_hits,
+            None => false,
+        }
+    }
+}
+
+/// MockBuilder configures a request expectation before it is registered
+/// with [`MockBuilder::create`].
+pub struct MockBuilder {
+    expectations: Arc<Mutex<Vec<Expectation>>>,
+    method: String,
+    path: String,
+    match_headers: Vec<(String, String)>,
+    match_body: Option<Body>,
+    status_line: String,
+    response_headers: Vec<(String, String)>,
+    response_body: Vec<u8>,
+    min_hits: usize,
+    max_hits: Option<usize>,
+}
+
+impl MockBuilder {
+    fn new(expectations: Arc<Mutex<Vec<Expectation>>>, method: &str, path: &str) -> MockBuilder {
+        MockBuilder {
+            expectations,
+            method: method.to_string(),
+            path: path.to_string(),
+            match_headers: Vec::new(),
+            match_body: None,
+            status_line: String::from("HTTP/1.1 200 OK"),
+            response_headers: Vec::new(),
+            response_body: Vec::new(),
+            min_hits: 1,
+            max_hits: None,
+        }
+    }
+
+    /// with_status() sets the status line served on a match, e.g. `200`
+    /// becomes `HTTP/1.1 200`; no reason phrase is added.
+    pub fn with_status(mut self, status: u16) -> MockBuilder {
+        self.status_line = format!("HTTP/1.1 {}", status);
+        self
+    }
+
+    /// with_header() adds a header to the response served on a match.
+    pub fn with_header(mut self, field: &str, value: &str) -> MockBuilder {
+        self.response_headers.push((field.to_string(), value.to_string()));
+        self
+    }
+
+    /// with_body() sets the UTF-8 response body served on a match.
+    pub fn with_body(mut self, body: &str) -> MockBuilder {
+        self.response_body = body.as_bytes().to_vec();
+        self
+    }
+
+    /// with_body_bytes() sets the raw-binary response body served on a
+    /// match.
+    pub fn with_body_bytes(mut self, body: &[u8]) -> MockBuilder {
+        self.response_body = body.to_vec();
+        self
+    }
+
+    /// match_header() requires the request to carry a header with this
+    /// field and value for the mock to match.
+    pub fn match_header(mut self, field: &str, value: &str) -> MockBuilder {
+        self.match_headers.push((field.to_string(), value.to_string()));
+        self
+    }
+
+    /// match_body() requires the request body to equal this UTF-8 text
+    /// for the mock to match.
+    pub fn match_body(mut self, body: &str) -> MockBuilder {
+        self.match_body = Some(Body::Text(body.to_string()));
+        self
+    }
+
+    /// match_body_bytes() requires the request body to equal these raw
+    /// bytes for the mock to match.
+    pub fn match_body_bytes(mut self, body: &[u8]) -> MockBuilder {
+        self.match_body = Some(Body::Binary(body.to_vec()));
+        self
+    }
+
+    /// expect_at_least() sets the minimum number of hits
+    /// [`Mock::is_missing_hits`] requires; the default is one.
+    pub fn expect_at_least(mut self, hits: usize) -> MockBuilder {
+        self.min_hits = hits;
+        self
+    }
+
+    /// expect_at_most() sets the maximum number of hits
+    /// [`Mock::exceeds_max_hits`] allows; unset by default, meaning
+    /// unbounded.
+    pub fn expect_at_most(mut self, hits: usize) -> MockBuilder {
+        self.max_hits = Some(hits);
+        self
+    }
+
+    /// create() registers the expectation with the `MockServer` it was
+    /// built from and returns a handle to assert on its hit count.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::mock::MockServer;
+    ///
+    /// let mut server = MockServer::start().unwrap();
+    /// let mock = server
+    ///     .mock("GET", "/hello")
+    ///     .with_status(200)
+    ///     .with_body("world")
+    ///     .create();
+    ///
+    /// assert_eq!(mock.hits(), 0);
+    ///
+    /// server.stop();
+    /// ```
+    pub fn create(self) -> Mock {
+        let hits = Arc::new(AtomicUsize::new(0));
+
+        let expectation = Expectation {
+            method: self.method,
+            path: self.path,
+            match_headers: self.match_headers,
+            match_body: self.match_body,
+            status_line: self.status_line,
+            response_headers: self.response_headers,
+            response_body: self.response_body,
+            hits: hits.clone(),
+        };
+
+        self.expectations.lock().unwrap().push(expectation);
+
+        Mock {
+            hits,
+            min_hits: self.min_hits,
+            max_hits: self.max_hits,
+        }
+    }
+}
+
+/// MockServer is an HTTP mock endpoint: register expectations with
+/// [`MockServer::mock`], then point an HTTP client at
+/// [`MockServer::local_addr`].
+pub struct MockServer {
+    server: Server,
+    expectations: Arc<Mutex<Vec<Expectation>>>,
+}
+
+impl MockServer {
+    /// start() binds a mock server to an OS-assigned ephemeral port.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::mock::MockServer;
+    ///
+    /// let server = MockServer::start().unwrap();
+    ///
+    /// assert!(server.listening_port().is_some());
+    ///
+    /// server.stop();
+    /// ```
+    pub fn start() -> Result<MockServer, String> {
+        let mut server = Server::new();
+        let expectations: Arc<Mutex<Vec<Expectation>>> = Arc::new(Mutex::new(Vec::new()));
+        let expectations_clone = expectations.clone();
+
+        server
+            .start("127.0.0.1:0", move |stream| {
+                handle_connection(stream, &expectations_clone)
+            })
+            .map_err(|e| e.to_string())?;
+
+        Ok(MockServer {
+            server,
+            expectations,
+        })
+    }
+
+    /// listening_port() returns the port the mock server is bound to.
+    pub fn listening_port(&self) -> Option<u16> {
+        self.server.listening_port()
+    }
+
+    /// local_addr() returns the address the mock server is bound to.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.local_addr()
+    }
+
+    /// mock() starts building a request expectation for `method` and
+    /// `path`; call [`MockBuilder::create`] to register it.
+    pub fn mock(&mut self, method: &str, path: &str) -> MockBuilder {
+        MockBuilder::new(self.expectations.clone(), method, path)
+    }
+
+    /// stop() stops the mock server.
+    pub fn stop(self) {
+        self.server.stop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::time::Duration;
+
+    fn request(port: u16, request: &str) -> String {
+        let mut stream =
+            TcpStream::connect_timeout(&SocketAddr::from(([127, 0, 0, 1], port)), Duration::new(3, 0))
+                .expect("failed to connect to mock server");
+
+        stream
+            .write_all(request.as_bytes())
+            .expect("failed to send request");
+
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .expect("failed to read response");
+
+        response
+    }
+
+    #[test]
+    fn matching_request_is_served_and_counted_test() {
+        let mut server = MockServer::start().unwrap();
+        let port = server.listening_port().unwrap();
+
+        let mock = server
+            .mock("GET", "/hello")
+            .with_status(200)
+            .with_body("world")
+            .create();
+
+        let response = request(port, "GET /hello HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200\r\n"));
+        assert!(response.ends_with("world"));
+
+        mock.assert_hits(1);
+        assert!(!mock.is_missing_hits());
+
+        server.stop();
+    }
+
+    #[test]
+    fn header_and_body_matching_test() {
+        let mut server = MockServer::start().unwrap();
+        let port = server.listening_port().unwrap();
+
+        let mock = server
+            .mock("POST", "/login")
+            .match_header("x-api-key", "secret")
+            .match_body("user=admin")
+            .with_status(201)
+            .create();
+
+        let wrong_header = request(
+            port,
+            "POST /login HTTP/1.1\r\ncontent-length: 10\r\n\r\nuser=admin",
+        );
+        assert!(wrong_header.starts_with("HTTP/1.1 501"));
+        assert_eq!(mock.hits(), 0);
+
+        let matching = request(
+            port,
+            "POST /login HTTP/1.1\r\nx-api-key: secret\r\ncontent-length: 10\r\n\r\nuser=admin",
+        );
+        assert!(matching.starts_with("HTTP/1.1 201"));
+
+        mock.assert_hits(1);
+
+        server.stop();
+    }
+
+    #[test]
+    fn unmatched_request_returns_not_implemented_test() {
+        let server = MockServer::start().unwrap();
+        let port = server.listening_port().unwrap();
+
+        let response = request(port, "GET /missing HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 501 Not Implemented\r\n"));
+
+        server.stop();
+    }
+
+    #[test]
+    fn is_missing_hits_reflects_expect_at_least_test() {
+        let mut server = MockServer::start().unwrap();
+
+        let mock = server
+            .mock("GET", "/hello")
+            .expect_at_least(2)
+            .create();
+
+        assert!(mock.is_missing_hits());
+
+        server.stop();
+    }
+
+    #[test]
+    fn exceeds_max_hits_reflects_expect_at_most_test() {
+        let mut server = MockServer::start().unwrap();
+        let port = server.listening_port().unwrap();
+
+        let mock = server.mock("GET", "/hello").expect_at_most(1).create();
+
+        assert!(!mock.exceeds_max_hits());
+
+        request(port, "GET /hello HTTP/1.1\r\n\r\n");
+        assert!(!mock.exceeds_max_hits());
+
+        request(port, "GET /hello HTTP/1.1\r\n\r\n");
+        assert!(mock.exceeds_max_hits());
+
+        server.stop();
+    }
+}
@@ -38,12 +38,10 @@ impl Server {
     /// # Examples
     /// ```
     /// use common_library::socket::server::Server;
-    /// use rand::Rng;
     /// use std::io::prelude::*;
     /// use std::net::TcpStream;
     ///
     /// let mut server = Server::new();
-    /// let port = rand::thread_rng().gen_range(10000..11000).to_string();
     ///
     /// let job = |mut stream: TcpStream| {
     ///     match stream.write("--- greeting ---\r\n".as_bytes()) {
@@ -68,13 +66,17 @@ impl Server {
     ///     }
     /// };
     ///
-    /// match server.start(String::from("0.0.0.0:") + &port, job) {
+    /// match server.start("127.0.0.1:0", job) {
     ///     Ok(_) => (),
     ///     Err(e) => assert!(false, "{}", e),
     /// };
     ///
+    /// let port = server
+    ///     .listening_port()
+    ///     .expect("server should be bound to a port");
+    ///
     /// {
-    ///     match TcpStream::connect(String::from("localhost:") + &port) {
+    ///     match TcpStream::connect(String::from("localhost:") + &port.to_string()) {
     ///         Ok(mut stream) => {
     ///             let mut buffer = [0; 1024];
     ///             match stream.read(&mut buffer) {
@@ -107,9 +109,23 @@ impl Server {
     ///
     /// server.stop();
     /// ```
-    pub fn start<T>(&mut self, address: T, job: fn(TcpStream)) -> Result<(), io::Error>
+    ///
+    /// Passing a port of `0`, e.g. `"127.0.0.1:0"`, asks the OS to bind an
+    /// ephemeral free port instead of a caller-chosen one. Use
+    /// [`Server::listening_port`] or [`Server::local_addr`] afterwards to
+    /// discover what was actually bound.
+    ///
+    /// `job` is wrapped in an `Arc` and each accepted connection runs the
+    /// shared closure on its own thread, so a slow or
+    /// blocking handler never holds up other clients. Because `job` is
+    /// shared across threads it must be `Send + Sync + 'static`, which
+    /// also lets it be a closure capturing shared state (connection
+    /// counters, routing tables, mock instructions) rather than just a
+    /// bare function pointer.
+    pub fn start<T, F>(&mut self, address: T, job: F) -> Result<(), io::Error>
     where
         T: ToSocketAddrs,
+        F: Fn(TcpStream) + Send + Sync + 'static,
     {
         let listener = TcpListener::bind(address)?;
 
@@ -117,6 +133,7 @@ impl Server {
 
         self.condition.store(false, Ordering::Relaxed);
         let condition_clone = self.condition.clone();
+        let job = Arc::new(job);
 
         self.handle = Some(thread::spawn(move || {
             for stream in listener.incoming() {
@@ -125,7 +142,10 @@ impl Server {
                 }
 
                 match stream {
-                    Ok(stream) => job(stream),
+                    Ok(stream) => {
+                        let job = job.clone();
+                        thread::spawn(move || job(stream));
+                    }
                     Err(e) => println!("{}", e),
                 }
             }
@@ -134,6 +154,57 @@ impl Server {
         Ok(())
     }
 
+    /// local_addr() returns the address the server is bound to, if it has
+    /// been started.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::server::Server;
+    /// use std::net::TcpStream;
+    ///
+    /// let mut server = Server::new();
+    ///
+    /// let job = |_stream: TcpStream| {};
+    ///
+    /// match server.start("127.0.0.1:0", job) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// assert!(server.local_addr().is_some());
+    ///
+    /// server.stop();
+    /// ```
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_address
+    }
+
+    /// listening_port() returns the port the server is bound to, if it has
+    /// been started. Useful after binding to `"127.0.0.1:0"` to discover
+    /// the OS-assigned ephemeral port.
+    ///
+    /// # Examples
+    /// ```
+    /// use common_library::socket::server::Server;
+    /// use std::net::TcpStream;
+    ///
+    /// let mut server = Server::new();
+    ///
+    /// let job = |_stream: TcpStream| {};
+    ///
+    /// match server.start("127.0.0.1:0", job) {
+    ///     Ok(_) => (),
+    ///     Err(e) => assert!(false, "{}", e),
+    /// };
+    ///
+    /// assert!(server.listening_port().is_some());
+    ///
+    /// server.stop();
+    /// ```
+    pub fn listening_port(&self) -> Option<u16> {
+        self.local_address.map(|address| address.port())
+    }
+
     /// Stop is stop the server.
     ///
     /// # Examples
@@ -164,10 +235,8 @@ impl Server {
 
 #[cfg(test)]
 mod tests {
-    extern crate rand;
-
     use super::*;
-    use rand::Rng;
+    use std::sync::atomic::AtomicUsize;
 
     #[test]
     fn start_test() {
@@ -195,15 +264,18 @@ mod tests {
         };
 
         let mut server = Server::new();
-        let port = rand::thread_rng().gen_range(10000..11000).to_string();
 
-        match server.start(String::from("0.0.0.0:") + &port, job) {
+        match server.start("127.0.0.1:0", job) {
             Ok(_) => (),
             Err(e) => assert!(false, "{}", e),
         };
 
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
         {
-            match TcpStream::connect(String::from("localhost:") + &port) {
+            match TcpStream::connect(String::from("localhost:") + &port.to_string()) {
                 Ok(mut stream) => {
                     let mut buffer = [0; 1024];
                     match stream.read(&mut buffer) {
@@ -243,4 +315,95 @@ mod tests {
 
         server.stop();
     }
+
+    #[test]
+    fn listening_port_test() {
+        let job = |_stream: TcpStream| {};
+
+        let mut server = Server::new();
+
+        assert_eq!(server.listening_port(), None);
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        assert!(server.listening_port().unwrap() > 0);
+
+        server.stop();
+    }
+
+    #[test]
+    fn local_addr_test() {
+        let job = |_stream: TcpStream| {};
+
+        let mut server = Server::new();
+
+        assert_eq!(server.local_addr(), None);
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        assert_eq!(
+            server.local_addr().unwrap().port(),
+            server.listening_port().unwrap()
+        );
+
+        server.stop();
+    }
+
+    #[test]
+    fn concurrent_connections_test() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let hits_clone = hits.clone();
+
+        let job = move |mut stream: TcpStream| {
+            hits_clone.fetch_add(1, Ordering::Relaxed);
+
+            match stream.write("ack\r\n".as_bytes()) {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+
+            match stream.flush() {
+                Ok(_) => (),
+                Err(e) => assert!(false, "{}", e),
+            }
+        };
+
+        let mut server = Server::new();
+
+        match server.start("127.0.0.1:0", job) {
+            Ok(_) => (),
+            Err(e) => assert!(false, "{}", e),
+        };
+
+        let port = server
+            .listening_port()
+            .expect("server should be bound to a port");
+
+        for _ in 0..3 {
+            match TcpStream::connect(String::from("localhost:") + &port.to_string()) {
+                Ok(mut stream) => {
+                    let mut buffer = [0; 1024];
+                    match stream.read(&mut buffer) {
+                        Ok(_) => (),
+                        Err(e) => assert!(false, "{}", e),
+                    }
+                    assert_eq!(
+                        String::from_utf8_lossy(&buffer.to_vec()).trim_end_matches(char::from(0)),
+                        "ack\r\n"
+                    );
+                }
+                Err(e) => assert!(false, "{}", e),
+            }
+        }
+
+        server.stop();
+
+        assert_eq!(hits.load(Ordering::Relaxed), 3);
+    }
 }